@@ -18,6 +18,23 @@ fn icbrt_via_f64(n: u64) -> u64 {
     cand - 1
 }
 
+// The original per-bit algorithm `integer_cbrt_checked` used before switching to Newton's
+// method, kept here to compare the two integer paths head-to-head.
+fn icbrt_via_bitwise(n: u128) -> u128 {
+    let num_bits = u128::BITS;
+    let mut x = n;
+    let mut result = 0u128;
+    for s in (0..num_bits).step_by(3).rev() {
+        result += result;
+        let b = 3 * result * (result + 1) + 1;
+        if (x >> s) >= b {
+            x -= b << s;
+            result += 1;
+        }
+    }
+    result
+}
+
 #[bench]
 fn icbrt_u64_small(b: &mut Bencher) {
     let small = 511u64;
@@ -72,6 +89,44 @@ fn icbrt_u128_large(b: &mut Bencher) {
     })
 }
 
+// `icbrt_via_bitwise` works on `u128` only: the `u64` benches below feed it `u64`-range values
+// as `u128`.
+#[bench]
+fn icbrt_bitwise_u64_small(b: &mut Bencher) {
+    let small = 511u128;
+    b.iter(|| {
+        let n = black_box(small);
+        assert_eq!(icbrt_via_bitwise(n), 7);
+    })
+}
+
+#[bench]
+fn icbrt_bitwise_u64_med(b: &mut Bencher) {
+    let med = 1_000_000_000_000_000u128; // 10^15
+    b.iter(|| {
+        let n = black_box(med);
+        assert_eq!(icbrt_via_bitwise(n), 100_000); // 10^5
+    })
+}
+
+#[bench]
+fn icbrt_bitwise_u64_large(b: &mut Bencher) {
+    let large = u64::MAX as u128;
+    b.iter(|| {
+        let n = black_box(large);
+        assert_eq!(icbrt_via_bitwise(n), 2642245);
+    })
+}
+
+#[bench]
+fn icbrt_bitwise_u128_large(b: &mut Bencher) {
+    let large = u128::MAX;
+    b.iter(|| {
+        let n = black_box(large);
+        assert_eq!(icbrt_via_bitwise(n), 6981463658331);
+    })
+}
+
 #[bench]
 fn icbrt_f64_small(b: &mut Bencher) {
     let small = 511u64;
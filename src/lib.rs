@@ -1,6 +1,6 @@
 //!
-//! This module contains the single trait [`IntegerCubeRoot`] and implements it for primitive
-//! integer types.
+//! This module contains the traits [`IntegerCubeRoot`] and [`IntegerRoot`], and implements them
+//! for primitive integer types.
 //!
 //! # Example
 //!
@@ -15,6 +15,13 @@
 //! ```
 //!
 //! [`IntegerCubeRoot`]: ./trait.IntegerCubeRoot.html
+//! [`IntegerRoot`]: ./trait.IntegerRoot.html
+//!
+//! # Features
+//!
+//! - `libm`: seeds `integer_cbrt`/`integer_cbrt_checked` with a `libm::cbrt` floating-point
+//!   estimate instead of iterating integer Newton's method, for faster roots on `no_std`
+//!   targets. Off by default.
 #![no_std]
 
 /// A trait implementing integer cube root.
@@ -37,6 +44,92 @@ pub trait IntegerCubeRoot {
     fn integer_cbrt_checked(&self) -> Option<Self>
     where
         Self: Sized;
+
+    /// Find the integer cube root `r`, along with the remainder `self - r^3`.
+    ///
+    /// The remainder is always non-negative and less than `(r+1)^3 - r^3`.
+    ///
+    /// # Panics
+    ///
+    /// For negative numbers (`i` family) this function will panic on negative input
+    fn integer_cbrt_rem(&self) -> (Self, Self)
+    where
+        Self: Sized + Copy + core::ops::Sub<Output = Self> + core::ops::Mul<Output = Self>,
+    {
+        let r = self.integer_cbrt();
+        let rem = *self - r * r * r;
+        (r, rem)
+    }
+
+    /// Returns `true` if `self` is a perfect cube.
+    ///
+    /// # Panics
+    ///
+    /// For negative numbers (`i` family) this function will panic on negative input
+    fn is_perfect_cube(&self) -> bool
+    where
+        Self: Sized + Copy + core::ops::Sub<Output = Self> + core::ops::Mul<Output = Self> + num_traits::Zero,
+    {
+        self.integer_cbrt_rem().1.is_zero()
+    }
+
+    /// Find the integer cube root, rounded up (`self.cbrt().ceil()`).
+    ///
+    /// # Panics
+    ///
+    /// For negative numbers (`i` family) this function will panic on negative input
+    fn integer_cbrt_ceil(&self) -> Self
+    where
+        Self: Sized
+            + Copy
+            + core::ops::Add<Output = Self>
+            + core::ops::Sub<Output = Self>
+            + core::ops::Mul<Output = Self>
+            + num_traits::Zero
+            + num_traits::One,
+    {
+        let (r, rem) = self.integer_cbrt_rem();
+        if rem.is_zero() {
+            r
+        } else {
+            r + Self::one()
+        }
+    }
+
+    /// Find the integer cube root `r` (rounded down), then return `r + 1` if `self` is at least
+    /// halfway between `r^3` and `(r+1)^3`, else `r`.
+    ///
+    /// This rounds by the midpoint of the two cubes, not of the two roots, so it is not always
+    /// the integer nearest to the real cube root: `43.integer_cbrt_round()` is `3`, although
+    /// `43.cbrt()` is about `3.503`.
+    ///
+    /// # Panics
+    ///
+    /// For negative numbers (`i` family) this function will panic on negative input
+    fn integer_cbrt_round(&self) -> Self
+    where
+        Self: Sized
+            + Copy
+            + PartialOrd
+            + core::ops::Add<Output = Self>
+            + core::ops::Sub<Output = Self>
+            + core::ops::Mul<Output = Self>
+            + num_traits::One,
+    {
+        let (r, rem) = self.integer_cbrt_rem();
+
+        // `delta = (r+1)^3 - r^3`, computed without ever materializing `(r+1)^3`.
+        let three = Self::one() + Self::one() + Self::one();
+        let delta = three * r * r + three * r + Self::one();
+
+        // Compare `rem` against `delta - rem` (i.e. `2 * rem >= delta`) rather than doubling
+        // `rem`, so this stays overflow-safe on `u128`/`i128`.
+        if rem >= delta - rem {
+            r + Self::one()
+        } else {
+            r
+        }
+    }
 }
 
 impl<T: num_traits::PrimInt> IntegerCubeRoot for T {
@@ -49,30 +142,236 @@ impl<T: num_traits::PrimInt> IntegerCubeRoot for T {
             _ => {}
         }
 
-        // Taken from: https://gist.github.com/anonymous/729557, and generalized to all
-        // integer primitive types.
-        let one = T::one();
-        let three = one + one + one;
-
-        let num_bits = T::zero().leading_zeros();
-        let mut x = *self;
-        let mut result = T::zero();
-        for s in (0..num_bits).step_by(3).rev() {
-            result = result + result;
-            let b = three * result * (result + one) + one;
-            if (x >> s as usize) >= b {
-                x = x - (b << s as usize);
-                result = result + one;
+        let result = positive_cbrt(*self);
+        debug_assert!(
+            result == bitwise_cbrt(*self),
+            "fast-path and bitwise cube root implementations disagree"
+        );
+        Some(result)
+    }
+}
+
+/// The fastest available cube root implementation for a strictly positive value: a
+/// `libm`-seeded float estimate when the `libm` feature is enabled, or pure integer Newton's
+/// method otherwise.
+#[cfg(feature = "libm")]
+fn positive_cbrt<T: num_traits::PrimInt>(x: T) -> T {
+    libm_seed_cbrt(x)
+}
+
+/// The fastest available cube root implementation for a strictly positive value: a
+/// `libm`-seeded float estimate when the `libm` feature is enabled, or pure integer Newton's
+/// method otherwise.
+#[cfg(not(feature = "libm"))]
+fn positive_cbrt<T: num_traits::PrimInt>(x: T) -> T {
+    newton_cbrt(x)
+}
+
+/// Seeds the cube root with a `libm::cbrt` floating-point estimate, then corrects it with at
+/// most one or two integer checks. This stays `no_std` and gives near-constant-time roots on
+/// platforms without an `std::f64::cbrt` to fall back on.
+#[cfg(feature = "libm")]
+fn libm_seed_cbrt<T: num_traits::PrimInt>(x: T) -> T {
+    use num_traits::NumCast;
+
+    let estimate = libm::cbrt(x.to_f64().unwrap_or(f64::INFINITY));
+    let mut cand: T = match NumCast::from(estimate) {
+        Some(cand) => cand,
+        // `estimate` doesn't fit `T` (e.g. it came out negative, NaN or infinite): fall back
+        // to the pure integer algorithm instead of looping from a nonsensical seed.
+        None => return newton_cbrt(x),
+    };
+
+    // `libm::cbrt` is only an approximation (`x` loses precision converting to `f64`, and the
+    // float estimate can round to either side of the true root): nudge `cand` down while it
+    // still overshoots, then up while the next candidate still fits. This takes at most one or
+    // two steps in practice.
+    while cube_checked(cand).is_none_or(|cube| cube > x) {
+        cand = cand - T::one();
+    }
+    while cube_checked(cand + T::one()).is_some_and(|cube| cube <= x) {
+        cand = cand + T::one();
+    }
+    cand
+}
+
+/// `cand^3`, or `None` on overflow.
+#[cfg(feature = "libm")]
+fn cube_checked<T: num_traits::PrimInt>(cand: T) -> Option<T> {
+    cand.checked_mul(&cand)
+        .and_then(|squared| squared.checked_mul(&cand))
+}
+
+/// Integer Newton's method for the cube root of a strictly positive value.
+///
+/// Converges quadratically, landing exactly on `floor(x.cbrt())` (`s^3 <= x < (s+1)^3`).
+fn newton_cbrt<T: num_traits::PrimInt>(x: T) -> T {
+    let one = T::one();
+    let two = one + one;
+    let three = two + one;
+
+    let num_bits = T::zero().leading_zeros();
+    let bit_length = num_bits - x.leading_zeros();
+
+    // `s` starts as an overestimate: `s >= floor(x.cbrt())`.
+    let mut s = one << (bit_length / 3 + 1) as usize;
+    loop {
+        let t = (two * s + x / (s * s)) / three;
+        if t >= s {
+            return s;
+        }
+        s = t;
+    }
+}
+
+/// The original per-bit cube root algorithm, kept as a reference implementation that the fast
+/// path is checked against in debug builds.
+///
+/// Taken from: https://gist.github.com/anonymous/729557, and generalized to all integer
+/// primitive types.
+fn bitwise_cbrt<T: num_traits::PrimInt>(x: T) -> T {
+    let one = T::one();
+    let three = one + one + one;
+
+    let num_bits = T::zero().leading_zeros();
+    let mut x = x;
+    let mut result = T::zero();
+    for s in (0..num_bits).step_by(3).rev() {
+        result = result + result;
+        let b = three * result * (result + one) + one;
+        if (x >> s as usize) >= b {
+            x = x - (b << s as usize);
+            result = result + one;
+        }
+    }
+    result
+}
+
+/// A trait implementing an arbitrary integer nth-root, generalizing [`IntegerCubeRoot`] to any
+/// degree.
+///
+/// [`IntegerCubeRoot`]: ./trait.IntegerCubeRoot.html
+pub trait IntegerRoot {
+    /// Find the integer `n`th root, i.e. `floor(self.pow(1/n))` for non-negative `self`. For
+    /// negative `self` (odd `n`) this is the root of the magnitude, negated, so it rounds toward
+    /// zero: `(-9).integer_root(3)` is `-2`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`, or if `n` is even and `self` is negative (`i` family).
+    fn integer_root(&self, n: u32) -> Self
+    where
+        Self: Sized,
+    {
+        self.integer_root_checked(n)
+            .expect("cannot calculate an even root of a negative number")
+    }
+
+    /// Find the integer `n`th root, returning `None` if `n` is even and `self` is negative (this
+    /// can never happen for unsigned types).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`.
+    fn integer_root_checked(&self, n: u32) -> Option<Self>
+    where
+        Self: Sized;
+}
+
+impl<T: num_traits::PrimInt> IntegerRoot for T {
+    fn integer_root_checked(&self, n: u32) -> Option<Self> {
+        use core::cmp::Ordering;
+
+        assert_ne!(n, 0, "0th root is not defined");
+
+        if n == 1 {
+            return Some(*self);
+        }
+
+        match self.cmp(&T::zero()) {
+            Ordering::Less if n.is_multiple_of(2) => None,
+            // `T::zero() - *self` would overflow for `T::min_value()`, whose magnitude isn't
+            // representable in `T` (e.g. `-128i8`). `T::max_value()` is one short of that
+            // magnitude, so its floor root coincides with `T::min_value()`'s true floor root
+            // unless that magnitude (`2^(num_bits - 1)`) is itself a perfect `n`th power, i.e.
+            // `n` divides `num_bits - 1` (e.g. `(-128i8).integer_root(7)`, since `128 == 2^7`);
+            // in that case the true root is one higher, which is corrected for below.
+            Ordering::Less => {
+                let magnitude = if *self == T::min_value() {
+                    T::max_value()
+                } else {
+                    T::zero() - *self
+                };
+                let mut root = positive_integer_root(magnitude, n);
+                if *self == T::min_value() {
+                    let num_bits = T::zero().leading_zeros();
+                    if (num_bits - 1).is_multiple_of(n) {
+                        root = root + T::one();
+                    }
+                }
+                Some(T::zero() - root)
             }
+            Ordering::Equal => Some(T::zero()),
+            Ordering::Greater => Some(positive_integer_root(*self, n)),
         }
-        Some(result)
     }
 }
 
+/// Integer Newton's method for the `n`th root of a strictly positive value, refined by a final
+/// correction pass to `floor(x.pow(1/n))`.
+///
+/// Newton's method alone would converge quadratically to the floor root, but once `s.pow(n - 1)`
+/// overflows `T` (which happens for even moderately large `n`, well before `s` is close to the
+/// true root), the fallback of halving `s` is not guaranteed to stay an overestimate, so the loop
+/// can stop one or more steps short. The correction pass below walks the candidate back to the
+/// exact floor root regardless of where Newton's method left it.
+fn positive_integer_root<T: num_traits::PrimInt>(x: T, n: u32) -> T {
+    let one = T::one();
+    let num_bits = T::zero().leading_zeros();
+    let bit_length = num_bits - x.leading_zeros();
+
+    // If `n` is at least the bit length of `x`, then `x < 2^n`, so (since `x >= 1` here) the
+    // floor root is `1`. This also sidesteps converting `n` into `T` below, which may not fit
+    // in `T` for a large `n` on a small integer type (e.g. `n > u8::MAX`); once past this
+    // check, `n < bit_length <= num_bits`, which always fits in `T`.
+    if bit_length <= n {
+        return one;
+    }
+
+    let n_t = T::from(n).expect("n fits in the target integer type");
+    let n_usize = n as usize;
+
+    // `s` starts as an overestimate: `s >= floor(x.pow(1/n))`.
+    let mut s = one << (bit_length / n + 1) as usize;
+    loop {
+        let s_pow = num_traits::checked_pow(s, n_usize - 1);
+        let t = match s_pow {
+            Some(s_pow) => ((n_t - one) * s + x / s_pow) / n_t,
+            // `s` is too large for `s.pow(n - 1)` to fit: shrink it before trying again. This
+            // can undershoot the true root, which the correction pass below fixes up.
+            None => s >> 1,
+        };
+        if t >= s {
+            break;
+        }
+        s = t;
+    }
+
+    // Restore the floor-root invariant (`s^n <= x < (s+1)^n`) directly, in case Newton's method
+    // undershot above: walk up while the next power still fits under `x`, then down while the
+    // current power overshoots it.
+    while num_traits::checked_pow(s + one, n_usize).is_some_and(|p| p <= x) {
+        s = s + one;
+    }
+    while num_traits::checked_pow(s, n_usize).is_none_or(|p| p > x) {
+        s = s - one;
+    }
+    s
+}
+
 #[cfg(test)]
 mod tests {
     use super::IntegerCubeRoot;
-    use core::{i8, u16, u64, u8};
 
     macro_rules! gen_tests {
         ($($type:ty => $fn_name:ident),*) => {
@@ -141,10 +440,130 @@ mod tests {
             (63, 3),
             (23_985_346_875, 2_883),
             (24_958_973_498_745, 29_224),
-            (i128::max_value(), 5_541_191_377_756),
+            (i128::MAX, 5_541_191_377_756),
         ];
         for &(in_, out) in tests.iter() {
             assert_eq!(in_.integer_cbrt(), out, "in {}", in_);
         }
     }
+
+    #[test]
+    fn integer_cbrt_rem_test() {
+        let tests: [(u64, u64, u64); 5] = [
+            (0, 0, 0),
+            (1, 1, 0),
+            (8, 2, 0),
+            (63, 3, 36),
+            (64, 4, 0),
+        ];
+        for &(in_, root, rem) in tests.iter() {
+            assert_eq!(in_.integer_cbrt_rem(), (root, rem), "in {}", in_);
+        }
+    }
+
+    #[test]
+    fn is_perfect_cube_test() {
+        assert!(0u64.is_perfect_cube());
+        assert!(1u64.is_perfect_cube());
+        assert!(8u64.is_perfect_cube());
+        assert!(27u64.is_perfect_cube());
+        assert!(!7u64.is_perfect_cube());
+        assert!(!63u64.is_perfect_cube());
+    }
+
+    #[test]
+    fn integer_cbrt_ceil_test() {
+        let tests: [(u64, u64); 6] = [(0, 0), (1, 1), (7, 2), (8, 2), (28, 4), (63, 4)];
+        for &(in_, out) in tests.iter() {
+            assert_eq!(in_.integer_cbrt_ceil(), out, "in {}", in_);
+        }
+    }
+
+    #[test]
+    fn integer_cbrt_round_test() {
+        let tests: [(u64, u64); 6] = [(0, 0), (1, 1), (27, 3), (28, 3), (50, 4), (63, 4)];
+        for &(in_, out) in tests.iter() {
+            assert_eq!(in_.integer_cbrt_round(), out, "in {}", in_);
+        }
+    }
+
+    mod integer_root {
+        use super::super::IntegerRoot;
+
+        #[test]
+        fn matches_known_roots() {
+            let tests: [(u64, u32, u64); 8] = [
+                (0, 2, 0),
+                (1, 5, 1),
+                (4, 2, 2),
+                (8, 3, 2),
+                (63, 3, 3),
+                (64, 3, 4),
+                (1_000_000, 2, 1000),
+                (u64::MAX, 2, 4_294_967_295),
+            ];
+            for &(in_, n, out) in tests.iter() {
+                assert_eq!(in_.integer_root(n), out, "in {} n {}", in_, n);
+            }
+        }
+
+        #[test]
+        fn degree_one_is_identity() {
+            assert_eq!(42u32.integer_root(1), 42);
+            assert_eq!((-42i32).integer_root(1), -42);
+        }
+
+        #[test]
+        fn odd_root_of_negative() {
+            assert_eq!((-8i32).integer_root(3), -2);
+        }
+
+        #[test]
+        fn odd_root_of_min_value_does_not_overflow() {
+            assert_eq!(i8::MIN.integer_root(3), -5);
+            assert_eq!(i32::MIN.integer_root(3), -1290);
+            assert_eq!(i128::MIN.integer_root(3), -5_541_191_377_756);
+        }
+
+        // `T::min_value()`'s magnitude (`2^(num_bits - 1)`) is a perfect `n`th power whenever `n`
+        // divides `num_bits - 1`, so these land exactly on a boundary the cases above dodge.
+        #[test]
+        fn odd_root_of_min_value_at_perfect_power_boundary() {
+            assert_eq!(i16::MIN.integer_root(3), -32);
+            assert_eq!(i64::MIN.integer_root(3), -2_097_152);
+            assert_eq!(i8::MIN.integer_root(7), -2);
+        }
+
+        #[test]
+        fn degree_larger_than_type_range_does_not_panic() {
+            assert_eq!(5u8.integer_root(300), 1);
+            assert_eq!(1u8.integer_root(300), 1);
+        }
+
+        // Regression tests for a seed-overflow bug: once `s.pow(n - 1)` overflows `T` (which
+        // happens for `n` well below the `degree_larger_than_type_range` guard above), Newton's
+        // method could undershoot the true floor root.
+        #[test]
+        fn large_degree_does_not_undershoot() {
+            assert_eq!(255u8.integer_root(5), 3);
+            assert_eq!(u128::MAX.integer_root(66), 3);
+        }
+
+        #[test]
+        fn odd_root_of_min_value_with_large_degree_does_not_undershoot() {
+            assert_eq!(i8::MIN.integer_root(5), -2);
+            assert_eq!(i128::MIN.integer_root(5), -44_275_338);
+        }
+
+        #[test]
+        fn even_root_of_negative_is_none() {
+            assert_eq!((-4i32).integer_root_checked(2), None);
+        }
+
+        #[test]
+        #[should_panic]
+        fn zeroth_root_panics() {
+            4u32.integer_root(0);
+        }
+    }
 }